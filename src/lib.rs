@@ -1,6 +1,21 @@
 //! This module contains the following traits.
 //! * [`ToWchar`] - Convert wchar(utf-16) array to wchar(utf-16) array from a string.
-//! * [`FromWchar`] - Converts wchar(utf-16) array to a string.
+//! * [`FromWchar`] - Converts wchar(utf-16) array to a string, or lossily (never
+//!   failing) via [`FromWchar::from_wchar_lossy`].
+//!
+//! [`ToWchar::to_wchar_checked`] and [`FromWchar::from_wchar_until_nul`] give a
+//! nul-aware alternative to the above for callers that need correct C-string
+//! semantics (a single terminator, no silently ignored interior NULs).
+//!
+//! `ToWchar` is also implemented for `OsStr`, and [`from_wchar_os`] converts
+//! back to an `OsString`, for a round trip that is lossless on Windows when
+//! code just needs to shuttle strings between Windows APIs without looking
+//! into them. Elsewhere both directions go through lossy UTF-8/UTF-16
+//! conversion.
+//!
+//! [`ToWchar32`] and [`FromWchar32`] mirror the above for the 32-bit
+//! `wchar_t` used by most Unix targets; [`WcharT`] aliases whichever width
+//! matches the current platform's `wchar_t`.
 //!
 //! # Examples
 //!
@@ -24,6 +39,20 @@ use std::ffi::{OsStr, OsString};
 /// To wchar(utf-16) trait to a wchar(utf-16) `Vec`.
 pub trait ToWchar {
     fn to_wchar(&self) -> Vec<u16>;
+
+    /// Convert a string into a nul-terminated wchar(utf-16) `Vec`, rejecting
+    /// sources that already contain an interior NUL.
+    ///
+    /// Unlike [`ToWchar::to_wchar`], the returned buffer is safe to hand to a C
+    /// `WCHAR*` API as a single nul-terminated string, since it's guaranteed
+    /// the terminator appended here is the only `0x0000` in the buffer.
+    fn to_wchar_checked(&self) -> Result<Vec<u16>, NulError> {
+        let wchar = self.to_wchar();
+        if wchar[..wchar.len() - 1].contains(&0) {
+            return Err(NulError);
+        }
+        Ok(wchar)
+    }
 }
 
 #[cfg(windows)]
@@ -37,10 +66,78 @@ impl ToWchar for str {
     }
 }
 
+#[cfg(not(windows))]
+impl ToWchar for str {
+    /// Convert a string into a wchar(utf-16) `Vec`.
+    #[inline]
+    fn to_wchar(&self) -> Vec<u16> {
+        use std::iter::once;
+        self.encode_utf16().chain(once(0)).collect()
+    }
+}
+
+#[cfg(windows)]
+impl ToWchar for OsStr {
+    /// Convert an `OsStr` into a wchar(utf-16) `Vec`, losslessly.
+    #[inline]
+    fn to_wchar(&self) -> Vec<u16> {
+        use std::iter::once;
+        use std::os::windows::ffi::OsStrExt;
+        self.encode_wide().chain(once(0)).collect()
+    }
+}
+
+#[cfg(not(windows))]
+impl ToWchar for OsStr {
+    /// Convert an `OsStr` into a wchar(utf-16) `Vec`.
+    #[inline]
+    fn to_wchar(&self) -> Vec<u16> {
+        self.to_string_lossy().to_wchar()
+    }
+}
+
+/// Error returned by [`ToWchar::to_wchar_checked`] when the source string
+/// contains an interior NUL character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NulError;
+
+impl std::fmt::Display for NulError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "nul byte found in source string")
+    }
+}
+
+impl std::error::Error for NulError {}
+
 /// From wchar(utf-16) trait to a `String`
 pub trait FromWchar {
     /// Convert a wchar(utf-16) to a `String`.
     fn from_wchar(wchar: &[u16]) -> Result<String, OsString>;
+
+    /// Convert a wchar(utf-16) array to a `String`, never failing.
+    ///
+    /// Any ill-formed UTF-16 (e.g. an isolated/unpaired surrogate) is
+    /// replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    fn from_wchar_lossy(wchar: &[u16]) -> String {
+        let wchar = match wchar.split_last() {
+            Some((0, rest)) => rest,
+            _ => wchar,
+        };
+        char::decode_utf16(wchar.iter().copied())
+            .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    /// Convert a wchar(utf-16) array to a `String`, decoding only up to the
+    /// first `0x0000`.
+    ///
+    /// Unlike [`FromWchar::from_wchar`], which trims *all* trailing NULs,
+    /// this follows C-string semantics: any data after the first NUL
+    /// (including further interior NULs) is discarded rather than decoded.
+    fn from_wchar_until_nul(wchar: &[u16]) -> Result<String, OsString> {
+        let end = wchar.iter().position(|&c| c == 0).unwrap_or(wchar.len());
+        Self::from_wchar(&wchar[..end])
+    }
 }
 
 #[cfg(windows)]
@@ -55,6 +152,111 @@ impl FromWchar for String {
     }
 }
 
+#[cfg(not(windows))]
+impl FromWchar for String {
+    /// Convert a wchar(utf-16) array to a `String`.
+    #[inline]
+    fn from_wchar(wchar: &[u16]) -> Result<String, OsString> {
+        String::from_utf16(wchar)
+            .map(|s| s.trim_end_matches('\0').into())
+            .map_err(|e| OsString::from(e.to_string()))
+    }
+}
+
+/// Convert a wchar(utf-16) array into an `OsString`, never failing.
+///
+/// On Windows this is lossless, preserving any ill-formed UTF-16 (e.g. an
+/// unpaired surrogate) that can't be represented in a Rust `String`. This
+/// makes it suitable for shuttling strings between Windows APIs without
+/// ever looking into them. Elsewhere, where `OsString` has no way to hold
+/// arbitrary UTF-16 code units, ill-formed UTF-16 is replaced with
+/// `U+FFFD`, same as [`FromWchar::from_wchar_lossy`].
+#[cfg(windows)]
+#[inline]
+pub fn from_wchar_os(wchar: &[u16]) -> OsString {
+    use std::os::windows::ffi::OsStringExt;
+    let wchar = match wchar.split_last() {
+        Some((0, rest)) => rest,
+        _ => wchar,
+    };
+    OsString::from_wide(wchar)
+}
+
+#[cfg(not(windows))]
+#[inline]
+pub fn from_wchar_os(wchar: &[u16]) -> OsString {
+    OsString::from(String::from_wchar_lossy(wchar))
+}
+
+/// To wchar(utf-32) trait to a wchar(utf-32) `Vec`.
+///
+/// On most Unix targets the C `wchar_t` is 32 bits wide, unlike the 16-bit
+/// `WCHAR` on Windows; use this instead of [`ToWchar`] for FFI with a
+/// `wchar_t*` there.
+pub trait ToWchar32 {
+    fn to_wchar32(&self) -> Vec<u32>;
+}
+
+impl ToWchar32 for str {
+    /// Convert a string into a wchar(utf-32) `Vec`.
+    #[inline]
+    fn to_wchar32(&self) -> Vec<u32> {
+        use std::iter::once;
+        self.chars().map(|c| c as u32).chain(once(0)).collect()
+    }
+}
+
+/// From wchar(utf-32) trait to a `String`.
+pub trait FromWchar32 {
+    /// Convert a wchar(utf-32) array to a `String`.
+    fn from_wchar32(wchar: &[u32]) -> Result<String, InvalidWchar32>;
+}
+
+impl FromWchar32 for String {
+    /// Convert a wchar(utf-32) array to a `String`.
+    #[inline]
+    fn from_wchar32(wchar: &[u32]) -> Result<String, InvalidWchar32> {
+        let wchar = match wchar.split_last() {
+            Some((0, rest)) => rest,
+            _ => wchar,
+        };
+        wchar
+            .iter()
+            .map(|&c| char::from_u32(c).ok_or(InvalidWchar32(c)))
+            .collect()
+    }
+}
+
+/// Error returned by [`FromWchar32::from_wchar32`] when a code unit is a
+/// surrogate (`U+D800..=U+DFFF`) or out of the Unicode scalar value range
+/// (greater than `U+10FFFF`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidWchar32(pub u32);
+
+impl std::fmt::Display for InvalidWchar32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid char value: {:#x}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidWchar32 {}
+
+/// The platform's native `wchar_t` width: `u16` on Windows, `u32` elsewhere.
+///
+/// Useful for generic FFI code that wants to pick between [`ToWchar`]/
+/// [`FromWchar`] and [`ToWchar32`]/[`FromWchar32`] without a `cfg` of its
+/// own.
+#[cfg(windows)]
+pub type WcharT = u16;
+
+/// The platform's native `wchar_t` width: `u16` on Windows, `u32` elsewhere.
+///
+/// Useful for generic FFI code that wants to pick between [`ToWchar`]/
+/// [`FromWchar`] and [`ToWchar32`]/[`FromWchar32`] without a `cfg` of its
+/// own.
+#[cfg(not(windows))]
+pub type WcharT = u32;
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -72,4 +274,90 @@ mod tests {
         let a: Vec<u16> = vec![0x0048, 0x0045, 0x004C, 0x004C, 0x004F, 0x0000];
         assert_eq!(String::from_wchar(&a).unwrap(), "HELLO")
     }
+
+    #[test]
+    fn test_from_wchar_lossy() {
+        use super::FromWchar;
+        // 0xD800 is an unpaired high surrogate.
+        let a: Vec<u16> = vec![0x0048, 0xD800, 0x0045, 0x0000];
+        assert_eq!(String::from_wchar_lossy(&a), "H\u{FFFD}E");
+    }
+
+    #[test]
+    fn test_to_wchar_checked() {
+        use super::{NulError, ToWchar};
+        assert_eq!(
+            "HELLO".to_wchar_checked(),
+            Ok(vec![0x0048, 0x0045, 0x004C, 0x004C, 0x004F, 0x0000])
+        );
+        assert_eq!("HE\0LLO".to_wchar_checked(), Err(NulError));
+    }
+
+    #[test]
+    fn test_from_wchar_until_nul() {
+        use super::FromWchar;
+        // A NUL in the middle, with trailing data that must be discarded.
+        let a: Vec<u16> = vec![0x0048, 0x0045, 0x0000, 0x004C, 0x004C];
+        assert_eq!(String::from_wchar_until_nul(&a).unwrap(), "HE");
+    }
+
+    #[test]
+    fn test_os_round_trip() {
+        use super::{from_wchar_os, ToWchar};
+        use std::ffi::OsStr;
+        let s = OsStr::new("HELLO");
+        assert_eq!(from_wchar_os(&s.to_wchar()), s);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_os_round_trip_unpaired_surrogate() {
+        use super::{from_wchar_os, ToWchar};
+        use std::ffi::OsString;
+        use std::os::windows::ffi::OsStringExt;
+        // 0xD800 is an unpaired high surrogate, which can't be represented
+        // in a Rust `String` but must survive the `OsString` round trip.
+        let s = OsString::from_wide(&[0xD800]);
+        assert_eq!(from_wchar_os(&s.to_wchar()), s);
+    }
+
+    #[test]
+    fn test_os_to_wchar_checked() {
+        use super::{NulError, ToWchar};
+        use std::ffi::OsStr;
+        assert_eq!(
+            OsStr::new("HELLO").to_wchar_checked(),
+            Ok(vec![0x0048, 0x0045, 0x004C, 0x004C, 0x004F, 0x0000])
+        );
+        assert_eq!(OsStr::new("HE\0LLO").to_wchar_checked(), Err(NulError));
+    }
+
+    #[test]
+    fn test_to_wchar32() {
+        use super::ToWchar32;
+        assert_eq!(
+            "HELLO".to_wchar32(),
+            vec![0x0048, 0x0045, 0x004C, 0x004C, 0x004F, 0x0000]
+        );
+    }
+
+    #[test]
+    fn test_from_wchar32() {
+        use super::FromWchar32;
+        let a: Vec<u32> = vec![0x0048, 0x0045, 0x004C, 0x004C, 0x004F, 0x0000];
+        assert_eq!(String::from_wchar32(&a).unwrap(), "HELLO");
+
+        // 0xD800 is a surrogate, which is never a valid scalar value on its own.
+        let b: Vec<u32> = vec![0x0048, 0xD800];
+        assert!(String::from_wchar32(&b).is_err());
+    }
+
+    #[test]
+    fn test_wchar32_round_trip_embedded_nul() {
+        use super::{FromWchar32, ToWchar32};
+        // Only the terminator `to_wchar32` appends should be stripped, not a
+        // NUL that's legitimately part of the source string.
+        let s = "ab\0\0";
+        assert_eq!(String::from_wchar32(&s.to_wchar32()).unwrap(), s);
+    }
 }
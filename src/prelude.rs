@@ -0,0 +1,5 @@
+//! Re-exports of the traits you'll want in scope for most call sites.
+
+pub use crate::{
+    from_wchar_os, FromWchar, FromWchar32, InvalidWchar32, NulError, ToWchar, ToWchar32, WcharT,
+};